@@ -1,11 +1,147 @@
 /// Marker Trait for embedded used fixed width types
 /// Acts as a bound for generic parameters that should
 /// be in the range of 8 to 32Bit signed and unsigned
-pub trait FixedWidth: Copy + PartialOrd {}
-
-impl FixedWidth for u8 {}
-impl FixedWidth for u16 {}
-impl FixedWidth for u32 {}
-impl FixedWidth for i32 {}
-impl FixedWidth for i16 {}
-impl FixedWidth for i8 {}
+///
+/// Also carries the handful of arithmetic operations that band/hysteresis
+/// style monitors need but that plain `+`/`-` cannot provide safely: limits
+/// configured near a type's extremes must not panic or silently wrap.
+pub trait FixedWidth: Copy + PartialOrd {
+    /// Adds `rhs` to `self`, clamping at the type's maximum instead of wrapping
+    fn saturating_add(self, rhs: Self) -> Self;
+    /// Subtracts `rhs` from `self`, clamping at the type's minimum instead of wrapping
+    fn saturating_sub(self, rhs: Self) -> Self;
+    /// Absolute difference between `self` and `rhs`, clamped to `Self::MAX`
+    /// Always non-negative and never panics or wraps, but is lossy at the
+    /// extremes for signed types: the true difference between e.g.
+    /// `i8::MIN` and `i8::MAX` is `255`, which does not fit in `i8`, so the
+    /// clamp reports `i8::MAX` instead. Fine for threshold comparisons (see
+    /// [crate::monitor::slope::SlopeMonitor]), but callers that need the
+    /// exact magnitude should widen manually (e.g. via [FixedWidth::to_i64])
+    /// rather than relying on this for anything but "too large" checks
+    fn abs_diff(self, rhs: Self) -> Self;
+    /// Widens `self` into an `i64`
+    /// Every `FixedWidth` type fits exactly, so this is the generic escape
+    /// hatch for code (e.g. [crate::common::fmt]) that needs plain integer
+    /// arithmetic - division, modulo - without knowing the concrete type
+    fn to_i64(self) -> i64;
+}
+
+impl FixedWidth for u8 {
+    fn saturating_add(self, rhs: Self) -> Self {
+        u8::saturating_add(self, rhs)
+    }
+    fn saturating_sub(self, rhs: Self) -> Self {
+        u8::saturating_sub(self, rhs)
+    }
+    fn abs_diff(self, rhs: Self) -> Self {
+        u8::abs_diff(self, rhs)
+    }
+    fn to_i64(self) -> i64 {
+        self as i64
+    }
+}
+
+impl FixedWidth for u16 {
+    fn saturating_add(self, rhs: Self) -> Self {
+        u16::saturating_add(self, rhs)
+    }
+    fn saturating_sub(self, rhs: Self) -> Self {
+        u16::saturating_sub(self, rhs)
+    }
+    fn abs_diff(self, rhs: Self) -> Self {
+        u16::abs_diff(self, rhs)
+    }
+    fn to_i64(self) -> i64 {
+        self as i64
+    }
+}
+
+impl FixedWidth for u32 {
+    fn saturating_add(self, rhs: Self) -> Self {
+        u32::saturating_add(self, rhs)
+    }
+    fn saturating_sub(self, rhs: Self) -> Self {
+        u32::saturating_sub(self, rhs)
+    }
+    fn abs_diff(self, rhs: Self) -> Self {
+        u32::abs_diff(self, rhs)
+    }
+    fn to_i64(self) -> i64 {
+        self as i64
+    }
+}
+
+impl FixedWidth for i8 {
+    fn saturating_add(self, rhs: Self) -> Self {
+        i8::saturating_add(self, rhs)
+    }
+    fn saturating_sub(self, rhs: Self) -> Self {
+        i8::saturating_sub(self, rhs)
+    }
+    fn abs_diff(self, rhs: Self) -> Self {
+        // `i8::abs_diff` returns `u8`; widen through `i16` instead so the
+        // result stays in `Self` and a saturating clamp can stand in for
+        // every difference above `i8::MAX` (e.g. `i8::MIN` vs `i8::MAX`, or
+        // `-1` vs `i8::MAX`) that does not fit back in `i8`
+        let diff = (self as i16 - rhs as i16).unsigned_abs();
+        diff.min(i8::MAX as u16) as i8
+    }
+    fn to_i64(self) -> i64 {
+        self as i64
+    }
+}
+
+impl FixedWidth for i16 {
+    fn saturating_add(self, rhs: Self) -> Self {
+        i16::saturating_add(self, rhs)
+    }
+    fn saturating_sub(self, rhs: Self) -> Self {
+        i16::saturating_sub(self, rhs)
+    }
+    fn abs_diff(self, rhs: Self) -> Self {
+        let diff = (self as i32 - rhs as i32).unsigned_abs();
+        diff.min(i16::MAX as u32) as i16
+    }
+    fn to_i64(self) -> i64 {
+        self as i64
+    }
+}
+
+impl FixedWidth for i32 {
+    fn saturating_add(self, rhs: Self) -> Self {
+        i32::saturating_add(self, rhs)
+    }
+    fn saturating_sub(self, rhs: Self) -> Self {
+        i32::saturating_sub(self, rhs)
+    }
+    fn abs_diff(self, rhs: Self) -> Self {
+        let diff = (self as i64 - rhs as i64).unsigned_abs();
+        diff.min(i32::MAX as u64) as i32
+    }
+    fn to_i64(self) -> i64 {
+        self as i64
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_saturating_add_clamps() {
+        assert_eq!(FixedWidth::saturating_add(250u8, 10u8), 255u8);
+        assert_eq!(FixedWidth::saturating_add(120i8, 10i8), 127i8);
+    }
+
+    #[test]
+    fn test_saturating_sub_clamps() {
+        assert_eq!(FixedWidth::saturating_sub(5u8, 10u8), 0u8);
+        assert_eq!(FixedWidth::saturating_sub(-120i8, 10i8), -128i8);
+    }
+
+    #[test]
+    fn test_abs_diff_never_wraps() {
+        assert_eq!(FixedWidth::abs_diff(5u8, 200u8), 195u8);
+        assert_eq!(FixedWidth::abs_diff(i8::MIN, i8::MAX), i8::MAX);
+    }
+}
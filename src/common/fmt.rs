@@ -0,0 +1,139 @@
+use crate::common::fixed_width::FixedWidth;
+
+/// Error returned by [format_fixed] when `buf` is too small to hold the
+/// rendered value
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct BufferTooSmall;
+
+/// Renders `val` as a fixed-point decimal with `D` fractional digits into
+/// `buf`, returning the written sub-slice
+/// No `core::fmt` float machinery and no allocation - this is the display
+/// path for the monitor subsystem (logging thresholds, min/max, windowed
+/// averages) on targets that lack a float formatter, given sensor readings
+/// that are already scaled integers (e.g. millivolts at `D == 3`).
+/// The magnitude is computed by widening through [FixedWidth::to_i64]
+/// rather than negating in `T`, so extremes like `i32::MIN` never risk a
+/// negation overflow (unlike [FixedWidth::abs_diff], which clamps instead
+/// of reporting the exact magnitude at a signed type's extremes - not what
+/// a decimal renderer needs). Digits are written into a scratch buffer
+/// least-significant-first, then reversed into `buf` in the order
+/// `[sign] integer_part ['.' fractional_part]`, the fractional part
+/// zero-padded to exactly `D` digits.
+/// # Example
+/// ```
+/// # use mylib::common::fmt::format_fixed;
+/// let mut buf = [0u8; 16];
+/// let out = format_fixed::<_, 3>(-1234i32, &mut buf).unwrap();
+/// assert_eq!(out, b"-1.234");
+/// ```
+pub fn format_fixed<T, const D: u32>(val: T, buf: &mut [u8]) -> Result<&mut [u8], BufferTooSmall>
+where
+    T: FixedWidth,
+{
+    let zero = val.saturating_sub(val);
+    let is_negative = val < zero;
+    // `i64` holds every `FixedWidth` value exactly, so widening first and
+    // negating in `i64` (rather than via the clamping `FixedWidth::abs_diff`)
+    // gives the exact magnitude even at a signed type's most negative value
+    let val_i64 = val.to_i64();
+    let magnitude = val_i64.unsigned_abs() as i64;
+
+    let scale = 10i64.pow(D);
+    let int_part = magnitude / scale;
+    let frac_part = magnitude % scale;
+
+    // Filled least-significant-to-most-significant, reversed into `buf` below
+    let mut scratch = [0u8; 32];
+    let mut len = 0usize;
+
+    for i in 0..D {
+        let digit = (frac_part / 10i64.pow(i)) % 10;
+        scratch[len] = b'0' + digit as u8;
+        len += 1;
+    }
+    if D > 0 {
+        scratch[len] = b'.';
+        len += 1;
+    }
+    let mut n = int_part;
+    loop {
+        scratch[len] = b'0' + (n % 10) as u8;
+        len += 1;
+        n /= 10;
+        if n == 0 {
+            break;
+        }
+    }
+    if is_negative {
+        scratch[len] = b'-';
+        len += 1;
+    }
+
+    if len > buf.len() {
+        return Err(BufferTooSmall);
+    }
+    for (dst, src) in buf[..len].iter_mut().zip(scratch[..len].iter().rev()) {
+        *dst = *src;
+    }
+    Ok(&mut buf[..len])
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_positive_with_fraction() {
+        let mut buf = [0u8; 16];
+        let out = format_fixed::<_, 2>(12345u32, &mut buf).unwrap();
+        assert_eq!(out, b"123.45");
+    }
+
+    #[test]
+    fn test_negative_with_fraction() {
+        let mut buf = [0u8; 16];
+        let out = format_fixed::<_, 3>(-1234i32, &mut buf).unwrap();
+        assert_eq!(out, b"-1.234");
+    }
+
+    #[test]
+    fn test_zero_fractional_digits() {
+        let mut buf = [0u8; 16];
+        let out = format_fixed::<_, 0>(42u8, &mut buf).unwrap();
+        assert_eq!(out, b"42");
+    }
+
+    #[test]
+    fn test_zero_pads_fraction() {
+        let mut buf = [0u8; 16];
+        let out = format_fixed::<_, 3>(1005i32, &mut buf).unwrap();
+        assert_eq!(out, b"1.005");
+    }
+
+    #[test]
+    fn test_buffer_too_small() {
+        let mut buf = [0u8; 2];
+        assert_eq!(format_fixed::<_, 2>(12345u32, &mut buf), Err(BufferTooSmall));
+    }
+
+    #[test]
+    fn test_i32_min_does_not_overflow() {
+        let mut buf = [0u8; 16];
+        let out = format_fixed::<_, 0>(i32::MIN, &mut buf).unwrap();
+        assert_eq!(out, b"-2147483648");
+    }
+
+    #[test]
+    fn test_i8_min_does_not_overflow() {
+        let mut buf = [0u8; 16];
+        let out = format_fixed::<_, 0>(i8::MIN, &mut buf).unwrap();
+        assert_eq!(out, b"-128");
+    }
+
+    #[test]
+    fn test_i16_min_does_not_overflow() {
+        let mut buf = [0u8; 16];
+        let out = format_fixed::<_, 0>(i16::MIN, &mut buf).unwrap();
+        assert_eq!(out, b"-32768");
+    }
+}
@@ -104,3 +104,111 @@ where
 pub struct SensorInner<T: FixedWidth> {
     pub val: T,
 }
+
+/// Multi-cast registry of [Observer]s for a single sensor
+/// Today a holder stores exactly one `&dyn Observer<T, U>`, so a single
+/// sensor can only notify one consumer. [Subject] fixes that: it holds a
+/// fixed `[Option<&dyn Observer<T, U>>; N]` and fans [Subject::notify] out
+/// to every registered observer, e.g. driving a [crate::monitor::level::level_hyst::LevelHyst],
+/// a rolling-window filter and a slope monitor off the same sensor without
+/// hand-rolling the dispatch loop.
+/// Observers are notified in registration order, so side-effecting observers
+/// behave deterministically.
+/// # Example
+/// ```
+/// # use crate::mylib::common::framework::*;
+/// # use core::cell::RefCell;
+/// struct Impler {
+///     int: RefCell<i32>,
+/// }
+/// impl Observer<i32, SensorInner<i32>> for Impler {
+///     fn dispatch(&self, sender_internals: &SensorInner<i32>, _val: i32) {
+///         self.int.replace(sender_internals.val);
+///     }
+/// }
+/// let a = Impler { int: RefCell::new(0) };
+/// let b = Impler { int: RefCell::new(0) };
+/// let mut subject = Subject::<i32, SensorInner<i32>, 2>::new();
+/// assert!(subject.register(&a));
+/// assert!(subject.register(&b));
+/// subject.notify(&SensorInner { val: 5 }, 5);
+/// assert_eq!(*a.int.borrow(), 5);
+/// assert_eq!(*b.int.borrow(), 5);
+/// ```
+pub struct Subject<'a, T: FixedWidth, U, const N: usize> {
+    observers: [Option<&'a dyn Observer<T, U>>; N],
+    // Number of slots filled so far, in registration order
+    len: usize,
+}
+
+impl<'a, T: FixedWidth, U, const N: usize> Subject<'a, T, U, N> {
+    /// Builds a new, empty [Subject]
+    pub fn new() -> Self {
+        Self {
+            observers: [None; N],
+            len: 0,
+        }
+    }
+
+    /// Registers an observer
+    /// Returns `false` without registering it if the registry is already full
+    pub fn register(&mut self, observer: &'a dyn Observer<T, U>) -> bool {
+        if self.len >= N {
+            return false;
+        }
+        self.observers[self.len] = Some(observer);
+        self.len += 1;
+        true
+    }
+
+    /// Dispatches `val` and `sender_internals` to every registered observer,
+    /// in the order they were registered
+    pub fn notify(&self, sender_internals: &U, val: T) {
+        for observer in self.observers[..self.len].iter().flatten() {
+            observer.dispatch(sender_internals, val);
+        }
+    }
+}
+
+impl<'a, T: FixedWidth, U, const N: usize> Default for Subject<'a, T, U, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use core::cell::Cell;
+
+    struct Recorder {
+        last: Cell<i32>,
+    }
+    impl Observer<i32, SensorInner<i32>> for Recorder {
+        fn dispatch(&self, sender_internals: &SensorInner<i32>, _val: i32) {
+            self.last.replace(sender_internals.val);
+        }
+    }
+
+    #[test]
+    fn test_notify_dispatches_to_all_registered() {
+        let a = Recorder { last: Cell::new(0) };
+        let b = Recorder { last: Cell::new(0) };
+        let mut subject = Subject::<i32, SensorInner<i32>, 2>::new();
+        assert!(subject.register(&a));
+        assert!(subject.register(&b));
+
+        subject.notify(&SensorInner { val: 42 }, 42);
+        assert_eq!(a.last.get(), 42);
+        assert_eq!(b.last.get(), 42);
+    }
+
+    #[test]
+    fn test_register_fails_when_full() {
+        let a = Recorder { last: Cell::new(0) };
+        let b = Recorder { last: Cell::new(0) };
+        let mut subject = Subject::<i32, SensorInner<i32>, 1>::new();
+        assert!(subject.register(&a));
+        assert!(!subject.register(&b));
+    }
+}
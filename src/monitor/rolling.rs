@@ -0,0 +1,319 @@
+use crate::common::fixed_width::FixedWidth;
+use crate::common::framework::{Observer, SensorGroupInner, SensorInner};
+use core::cell::Cell;
+
+/// One entry in an [IndexDeque]: a sample's slot in the `samples` ring
+/// paired with its age-comparable sequence number
+/// `slot` is what indexes `RollingExtremes::samples` (bounded `[0, N)`,
+/// exactly like [crate::monitor::window::Window]'s `write_idx`); `seq` is
+/// only ever compared via wrapping subtraction, never used as an index.
+/// They are tracked separately because `2^32` (where `seq` wraps) is not in
+/// general a multiple of `N`, so `seq as usize % N` would drift out of sync
+/// with `slot` across a wraparound and alias the wrong sample.
+#[derive(Debug, Clone, Copy)]
+struct IndexEntry {
+    slot: usize,
+    seq: u32,
+}
+
+/// Fixed-capacity monotonic deque over sample indices
+/// Backed by a `[IndexEntry; N]` ring buffer - no heap, no `Vec`. Only ever
+/// holds at most `N` entries since at most `N` samples are in the window at
+/// once. `head`/`len` are a bounded ring cursor into `buf` (always in
+/// `[0, N]`) rather than ever-growing counters, so they cannot overflow no
+/// matter how long the deque lives.
+#[derive(Debug, Clone, Copy)]
+struct IndexDeque<const N: usize> {
+    buf: [IndexEntry; N],
+    // Ring cursor into `buf`: `head` is the oldest entry, `len` the count
+    head: usize,
+    len: usize,
+}
+
+impl<const N: usize> IndexDeque<N> {
+    fn new() -> Self {
+        Self {
+            buf: [IndexEntry { slot: 0, seq: 0 }; N],
+            head: 0,
+            len: 0,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn front(&self) -> IndexEntry {
+        self.buf[self.head]
+    }
+
+    fn back(&self) -> IndexEntry {
+        self.buf[(self.head + self.len - 1) % N]
+    }
+
+    fn push_back(&mut self, entry: IndexEntry) {
+        self.buf[(self.head + self.len) % N] = entry;
+        self.len += 1;
+    }
+
+    fn pop_back(&mut self) {
+        self.len -= 1;
+    }
+
+    fn pop_front(&mut self) {
+        self.head = (self.head + 1) % N;
+        self.len -= 1;
+    }
+}
+
+/// Reports the minimum and maximum over the most recent `N` samples in O(1)
+/// amortized time per sample, using two monotonic deques of sample indices:
+/// a non-decreasing deque tracks the minimum, a non-increasing deque tracks
+/// the maximum. On each [RollingExtremes::push], expired indices (outside
+/// the last `N` samples) are dropped from the front and any index whose
+/// value is dominated by the new one is dropped from the back, so a
+/// long-running embedded loop never pays O(N) per sample the way a naive
+/// rescan would.
+/// Each push is tagged with a wrapping `u32` sequence number for expiry:
+/// only the *difference* between the current sequence number and a stored
+/// one is ever examined (via wrapping subtraction), never its absolute
+/// value, so the counter wrapping after `u32::MAX` pushes never corrupts
+/// expiry - the kind of long-running embedded loop this type targets must
+/// not have a hard sample-count ceiling. The sequence number is never used
+/// to index `samples` directly (see [IndexEntry]); a separate bounded
+/// `write_idx`, mirroring [crate::monitor::window::Window], does that.
+/// Populates a [SensorGroupInner] on every [RollingExtremes::push] and, if
+/// built via [RollingExtremes::with_downstream], relays it to a downstream
+/// observer - e.g. a [crate::monitor::level::level_hyst::LevelHyst] that
+/// thresholds on the windowed extreme rather than the instantaneous value.
+/// Built via [RollingExtremes::new] it has no downstream and the group
+/// must be polled from [RollingExtremes::push]'s return value.
+/// # Example
+/// ```
+/// # use mylib::monitor::rolling::RollingExtremes;
+/// let extremes = RollingExtremes::<i32, 3>::new();
+/// extremes.push(5);
+/// extremes.push(1);
+/// let group = extremes.push(3);
+/// assert_eq!(group.get_min(), 1);
+/// assert_eq!(group.get_max(), 5);
+/// ```
+pub struct RollingExtremes<'a, T, const N: usize>
+where
+    T: FixedWidth,
+{
+    samples: Cell<[T; N]>,
+    min_deque: Cell<IndexDeque<N>>,
+    max_deque: Cell<IndexDeque<N>>,
+    // Index the next `push` will write into, bounded in `[0, N)`
+    write_idx: Cell<usize>,
+    // Wrapping sequence number used only for deque age comparisons - see the type-level doc comment
+    next_seq: Cell<u32>,
+    downstream: Option<&'a dyn Observer<T, SensorGroupInner<T>>>,
+}
+
+impl<'a, T, const N: usize> RollingExtremes<'a, T, N>
+where
+    T: FixedWidth,
+{
+    const ASSERT_N_NONZERO: () = assert!(N > 0, "RollingExtremes requires N > 0");
+
+    /// Builds a new, empty [RollingExtremes]
+    /// Its result is a sink: observable only via [RollingExtremes::push]'s
+    /// return value
+    pub fn new() -> Self
+    where
+        T: Default,
+    {
+        Self::with_downstream_opt(None)
+    }
+
+    /// Builds a new, empty [RollingExtremes] that, in addition to
+    /// [RollingExtremes::push]'s return value, relays each updated group to
+    /// `downstream` as soon as it is computed
+    /// # Example
+    /// ```
+    /// # use mylib::monitor::rolling::RollingExtremes;
+    /// # use mylib::monitor::level::{level_simple::*, level_hyst::*};
+    /// let level = LevelHyst::new(10, 15, LevelState::UNDER);
+    /// let extremes = RollingExtremes::<i32, 2>::with_downstream(&level);
+    /// extremes.push(6);
+    /// extremes.push(20);
+    /// assert_eq!(level.get_state(), LevelState::OVER);
+    /// ```
+    pub fn with_downstream(downstream: &'a dyn Observer<T, SensorGroupInner<T>>) -> Self
+    where
+        T: Default,
+    {
+        Self::with_downstream_opt(Some(downstream))
+    }
+
+    fn with_downstream_opt(downstream: Option<&'a dyn Observer<T, SensorGroupInner<T>>>) -> Self
+    where
+        T: Default,
+    {
+        #[allow(clippy::let_unit_value)]
+        let _ = Self::ASSERT_N_NONZERO;
+        Self {
+            samples: Cell::new([T::default(); N]),
+            min_deque: Cell::new(IndexDeque::new()),
+            max_deque: Cell::new(IndexDeque::new()),
+            write_idx: Cell::new(0),
+            next_seq: Cell::new(0),
+            downstream,
+        }
+    }
+
+    /// Pushes a new sample, relays the updated min/max to the downstream
+    /// observer if one was registered, and returns it
+    pub fn push(&self, val: T) -> SensorGroupInner<T> {
+        let slot = self.write_idx.get();
+        let seq = self.next_seq.get();
+        let mut samples = self.samples.get();
+        samples[slot] = val;
+        self.samples.set(samples);
+
+        let entry = IndexEntry { slot, seq };
+
+        // Expiry must run before `push_back`: the deque can already hold up
+        // to `N` entries, and `push_back` writes into the slot the next
+        // expiry would free, aliasing the front until it is popped.
+        // `wrapping_sub` keeps the age comparison correct across `seq`
+        // wrapping past `u32::MAX`.
+        let mut min_deque = self.min_deque.get();
+        while !min_deque.is_empty() && seq.wrapping_sub(min_deque.front().seq) >= N as u32 {
+            min_deque.pop_front();
+        }
+        while !min_deque.is_empty() && samples[min_deque.back().slot] > val {
+            min_deque.pop_back();
+        }
+        min_deque.push_back(entry);
+        self.min_deque.set(min_deque);
+
+        let mut max_deque = self.max_deque.get();
+        while !max_deque.is_empty() && seq.wrapping_sub(max_deque.front().seq) >= N as u32 {
+            max_deque.pop_front();
+        }
+        while !max_deque.is_empty() && samples[max_deque.back().slot] < val {
+            max_deque.pop_back();
+        }
+        max_deque.push_back(entry);
+        self.max_deque.set(max_deque);
+
+        self.write_idx.set((slot + 1) % N);
+        self.next_seq.set(seq.wrapping_add(1));
+
+        let group = SensorGroupInner {
+            min: samples[min_deque.front().slot],
+            max: samples[max_deque.front().slot],
+        };
+        if let Some(downstream) = self.downstream {
+            downstream.dispatch(&group, val);
+        }
+        group
+    }
+}
+
+impl<'a, T, const N: usize> Observer<T, SensorInner<T>> for RollingExtremes<'a, T, N>
+where
+    T: FixedWidth,
+{
+    fn dispatch(&self, sender: &SensorInner<T>, val: T) {
+        let _ = self.push(sender.val);
+        let _ = val;
+    }
+}
+
+impl<'a, T, const N: usize> Default for RollingExtremes<'a, T, N>
+where
+    T: FixedWidth + Default,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::monitor::level::{level_hyst::LevelHyst, level_simple::LevelState};
+
+    #[test]
+    fn test_min_max_within_window() {
+        let extremes = RollingExtremes::<i32, 3>::new();
+        extremes.push(5);
+        extremes.push(1);
+        let group = extremes.push(3);
+        assert_eq!(group.get_min(), 1);
+        assert_eq!(group.get_max(), 5);
+    }
+
+    #[test]
+    fn test_extremes_expire_out_of_window() {
+        let extremes = RollingExtremes::<i32, 3>::new();
+        extremes.push(5);
+        extremes.push(1);
+        extremes.push(3);
+        // 5 falls out of the window of the most recent 3 samples
+        let group = extremes.push(2);
+        assert_eq!(group.get_min(), 1);
+        assert_eq!(group.get_max(), 3);
+    }
+
+    #[test]
+    fn test_monotonic_ramp_keeps_correct_extremes() {
+        // Regression test: a strictly increasing run never pops from the
+        // back of the min-deque, so it only shrinks via front expiry - this
+        // exercises a deque that is genuinely full before the next push.
+        let extremes = RollingExtremes::<i32, 3>::new();
+        extremes.push(1);
+        extremes.push(2);
+        extremes.push(3);
+        let group = extremes.push(4);
+        assert_eq!(group.get_min(), 2);
+        assert_eq!(group.get_max(), 4);
+
+        let group = extremes.push(5);
+        assert_eq!(group.get_min(), 3);
+        assert_eq!(group.get_max(), 5);
+    }
+
+    #[test]
+    fn test_observer_pattern() {
+        let extremes = RollingExtremes::<i32, 2>::new();
+        extremes.dispatch(&SensorInner { val: 7 }, 7);
+        let group = extremes.push(4);
+        assert_eq!(group.get_min(), 4);
+        assert_eq!(group.get_max(), 7);
+    }
+
+    #[test]
+    fn test_relays_to_downstream_observer() {
+        let level = LevelHyst::new(10, 15, LevelState::UNDER);
+        let extremes = RollingExtremes::<i32, 2>::with_downstream(&level);
+        extremes.push(6);
+        assert_eq!(level.get_state(), LevelState::UNDER);
+        extremes.push(20);
+        assert_eq!(level.get_state(), LevelState::OVER);
+    }
+
+    #[test]
+    fn test_sequence_number_wraparound_does_not_corrupt_expiry() {
+        // Drives the internal sequence counter to the brink of wrapping and
+        // past it; expiry uses wrapping subtraction so the window stays
+        // correct regardless, and sample storage is indexed by the separate
+        // bounded `write_idx`, not by the wrapping sequence number
+        let extremes = RollingExtremes::<i32, 3>::new();
+        // Force the sequence counter right up to the wraparound boundary
+        extremes.next_seq.set(u32::MAX - 1);
+        extremes.push(10);
+        extremes.push(20);
+        // Sequence counter now wraps from u32::MAX back to 0
+        let group = extremes.push(30);
+        assert_eq!(group.get_min(), 10);
+        assert_eq!(group.get_max(), 30);
+        let group = extremes.push(40);
+        assert_eq!(group.get_min(), 20);
+        assert_eq!(group.get_max(), 40);
+    }
+}
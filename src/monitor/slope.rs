@@ -0,0 +1,84 @@
+use crate::common::fixed_width::FixedWidth;
+use core::cell::Cell;
+
+/// Slope verdict Type
+/// Must be [PartialEq] so it can be compared with ==
+/// Must be [Clone] and [Copy] so it works with Cell and is trivial for a simple numeric enum
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum SlopeState {
+    STABLE = 0,
+    FAST = 1,
+}
+
+/// Rate-of-change monitor: flags samples that move more than a configured
+/// threshold from one reading to the next
+/// Complements [crate::monitor::level::level_hyst::LevelHyst] which only
+/// looks at the instantaneous value - this catches transients/spikes that
+/// never cross a level threshold. Uses [FixedWidth::abs_diff] so a delta
+/// near the type's extremes saturates instead of overflowing.
+pub struct SlopeMonitor<T>
+where
+    T: FixedWidth,
+{
+    // Must be a Cell to provide interior mutability for the Observer pattern
+    prev: Cell<Option<T>>,
+    // Per-sample delta that triggers [SlopeState::FAST]
+    threshold: T,
+}
+
+impl<T> SlopeMonitor<T>
+where
+    T: FixedWidth,
+{
+    /// Builds a new [SlopeMonitor] with the given per-sample threshold
+    /// # Example
+    /// ```
+    /// # use mylib::monitor::slope::*;
+    /// let slope = SlopeMonitor::new(50u8);
+    /// assert_eq!(slope.check(10), SlopeState::STABLE);
+    /// assert_eq!(slope.check(200), SlopeState::FAST);
+    /// ```
+    pub fn new(threshold: T) -> Self {
+        Self {
+            prev: Cell::new(None),
+            threshold,
+        }
+    }
+
+    /// Checks `val` against the previous sample
+    /// Returns [SlopeState::STABLE] for the first sample, since there is no
+    /// previous value yet to compare against
+    pub fn check(&self, val: T) -> SlopeState {
+        let state = match self.prev.get() {
+            Some(prev) if val.abs_diff(prev) > self.threshold => SlopeState::FAST,
+            _ => SlopeState::STABLE,
+        };
+        self.prev.replace(Some(val));
+        state
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_first_sample_is_stable() {
+        let slope = SlopeMonitor::new(50u8);
+        assert_eq!(slope.check(10), SlopeState::STABLE);
+    }
+
+    #[test]
+    fn test_spike_is_flagged() {
+        let slope = SlopeMonitor::new(50u8);
+        slope.check(5);
+        assert_eq!(slope.check(200), SlopeState::FAST);
+    }
+
+    #[test]
+    fn test_abs_diff_saturates_instead_of_wrapping() {
+        let slope = SlopeMonitor::new(10i8);
+        slope.check(i8::MIN);
+        assert_eq!(slope.check(i8::MAX), SlopeState::FAST);
+    }
+}
@@ -0,0 +1,195 @@
+use crate::common::fixed_width::FixedWidth;
+use crate::common::framework::{Observer, SensorInner};
+use core::cell::Cell;
+
+/// Sliding-window adapter that maps a closure over the last `N` samples
+/// Buffers samples in a fixed `[Option<T>; N]` ring - no heap, no `Vec`.
+/// Each time the window becomes full it materializes the samples in
+/// chronological order (oldest -> newest) and calls the closure `f`,
+/// storing the result for retrieval via [Window::get_result].
+/// Implements [Observer] over [SensorInner] so it can be chained directly
+/// off a sensor. Built via [Window::with_downstream], it also relays each
+/// result (wrapped in a [SensorInner]) to a downstream observer as soon as
+/// it is computed, e.g. feeding a rolling average into a downstream
+/// [crate::monitor::level::level_hyst::LevelHyst] without the caller
+/// having to poll. Built via [Window::new] it has no downstream and is a
+/// pure sink: the result is only observable through [Window::get_result].
+/// # Example
+/// ```
+/// # use mylib::monitor::window::Window;
+/// let window = Window::<i32, _, i32, 3>::new(|samples| samples.iter().sum());
+/// assert_eq!(window.push(1), None);
+/// assert_eq!(window.push(2), None);
+/// assert_eq!(window.push(3), Some(6));
+/// assert_eq!(window.push(4), Some(9));
+/// ```
+pub struct Window<'a, T, F, R, const N: usize>
+where
+    T: FixedWidth,
+    F: Fn(&[T; N]) -> R,
+    R: FixedWidth,
+{
+    samples: Cell<[Option<T>; N]>,
+    // Index the next `push` will write into
+    write_idx: Cell<usize>,
+    // Number of samples seen so far, saturates at `N`
+    filled: Cell<usize>,
+    f: F,
+    result: Cell<Option<R>>,
+    downstream: Option<&'a dyn Observer<R, SensorInner<R>>>,
+}
+
+impl<'a, T, F, R, const N: usize> Window<'a, T, F, R, N>
+where
+    T: FixedWidth,
+    F: Fn(&[T; N]) -> R,
+    R: FixedWidth,
+{
+    // Compile-time rejection of `N == 0`, referenced from `new` to force evaluation
+    const ASSERT_N_NONZERO: () = assert!(N > 0, "Window requires N > 0");
+
+    /// Builds a new [Window] that applies `f` to the window once it fills
+    /// Its result is a sink: observable only via [Window::get_result]
+    pub fn new(f: F) -> Self {
+        Self::with_downstream_opt(f, None)
+    }
+
+    /// Builds a new [Window] that, in addition to [Window::get_result],
+    /// relays each result to `downstream` as soon as it is computed
+    /// # Example
+    /// ```
+    /// # use mylib::monitor::window::Window;
+    /// # use mylib::monitor::level::{level_simple::*, level_hyst::*};
+    /// let level = LevelHyst::new(10, 15, LevelState::UNDER);
+    /// let window = Window::<i32, _, i32, 2>::with_downstream(
+    ///     |samples| samples.iter().sum(),
+    ///     &level,
+    /// );
+    /// window.push(6);
+    /// window.push(10);
+    /// assert_eq!(level.get_state(), LevelState::OVER);
+    /// ```
+    pub fn with_downstream(f: F, downstream: &'a dyn Observer<R, SensorInner<R>>) -> Self {
+        Self::with_downstream_opt(f, Some(downstream))
+    }
+
+    fn with_downstream_opt(f: F, downstream: Option<&'a dyn Observer<R, SensorInner<R>>>) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let _ = Self::ASSERT_N_NONZERO;
+        Self {
+            samples: Cell::new([None; N]),
+            write_idx: Cell::new(0),
+            filled: Cell::new(0),
+            f,
+            result: Cell::new(None),
+            downstream,
+        }
+    }
+
+    /// Pushes a new sample into the window
+    /// Returns `None` while the window is still filling, otherwise applies
+    /// `f` to the current window (oldest -> newest), relays the result to
+    /// the downstream observer if one was registered, and returns it
+    pub fn push(&self, val: T) -> Option<R> {
+        let idx = self.write_idx.get();
+        let mut samples = self.samples.get();
+        samples[idx] = Some(val);
+        self.samples.set(samples);
+
+        let next_idx = (idx + 1) % N;
+        self.write_idx.set(next_idx);
+
+        let filled = self.filled.get();
+        if filled < N {
+            self.filled.set(filled + 1);
+        }
+        if self.filled.get() < N {
+            return None;
+        }
+
+        // `val` is only used as a filler here; every slot is overwritten
+        // below since the window is known to be full at this point
+        let mut ordered = [val; N];
+        for (i, slot) in ordered.iter_mut().enumerate() {
+            let pos = (next_idx + i) % N;
+            *slot = samples[pos].expect("full window slot must be populated");
+        }
+
+        let r = (self.f)(&ordered);
+        self.result.set(Some(r));
+        if let Some(downstream) = self.downstream {
+            downstream.dispatch(&SensorInner { val: r }, r);
+        }
+        Some(r)
+    }
+
+    /// Returns the result of the last time the window filled, if any
+    pub fn get_result(&self) -> Option<R> {
+        self.result.get()
+    }
+}
+
+impl<'a, T, F, R, const N: usize> Observer<T, SensorInner<T>> for Window<'a, T, F, R, N>
+where
+    T: FixedWidth,
+    F: Fn(&[T; N]) -> R,
+    R: FixedWidth,
+{
+    fn dispatch(&self, sender: &SensorInner<T>, val: T) {
+        let _ = self.push(sender.val);
+        let _ = val;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::monitor::level::{level_hyst::LevelHyst, level_simple::LevelState};
+
+    #[test]
+    fn test_fills_then_emits() {
+        let window = Window::<i32, _, i32, 3>::new(|samples: &[i32; 3]| samples.iter().sum());
+        assert_eq!(window.push(1), None);
+        assert_eq!(window.push(2), None);
+        assert_eq!(window.push(3), Some(6));
+    }
+
+    #[test]
+    fn test_chronological_order_after_wraparound() {
+        // Encodes the ordered samples into a single FixedWidth value
+        // (hundreds/tens/units) so the test can assert on ordering directly
+        let window = Window::<i32, _, i32, 3>::new(|samples: &[i32; 3]| {
+            samples[0] * 100 + samples[1] * 10 + samples[2]
+        });
+        window.push(1);
+        window.push(2);
+        window.push(3);
+        // Slides forward one sample, dropping the oldest (1)
+        assert_eq!(window.push(4), Some(234));
+        assert_eq!(window.push(5), Some(345));
+    }
+
+    #[test]
+    fn test_observer_pattern() {
+        let window = Window::<i32, _, i32, 2>::new(|samples: &[i32; 2]| samples[0] + samples[1]);
+        let inner = SensorInner { val: 10 };
+        window.dispatch(&inner, 10);
+        assert_eq!(window.get_result(), None);
+        let inner = SensorInner { val: 20 };
+        window.dispatch(&inner, 20);
+        assert_eq!(window.get_result(), Some(30));
+    }
+
+    #[test]
+    fn test_relays_to_downstream_observer() {
+        let level = LevelHyst::new(10, 15, LevelState::UNDER);
+        let window = Window::<i32, _, i32, 2>::with_downstream(
+            |samples: &[i32; 2]| samples.iter().sum(),
+            &level,
+        );
+        assert_eq!(window.push(6), None);
+        assert_eq!(level.get_state(), LevelState::UNDER);
+        assert_eq!(window.push(10), Some(16));
+        assert_eq!(level.get_state(), LevelState::OVER);
+    }
+}